@@ -0,0 +1,156 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum number of entries kept in the in-memory ring buffer. Once full,
+/// the oldest entry is dropped to make room for the newest, so a
+/// long-running service can leave audit logging enabled without leaking
+/// memory.
+const MAX_LOG_ENTRIES: usize = 1000;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// A single recorded authorization call.
+struct AuditLogEntry {
+    id: String,
+    timestamp_unix_ms: u64,
+    principal: Option<String>,
+    action: Option<String>,
+    resource: Option<String>,
+    decision: Option<String>,
+    determining_policies: Vec<String>,
+    errors: Vec<String>,
+}
+
+impl AuditLogEntry {
+    fn to_pydict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("id", &self.id)?;
+        dict.set_item("timestamp_unix_ms", self.timestamp_unix_ms)?;
+        dict.set_item("principal", &self.principal)?;
+        dict.set_item("action", &self.action)?;
+        dict.set_item("resource", &self.resource)?;
+        dict.set_item("decision", &self.decision)?;
+        dict.set_item("determining_policies", &self.determining_policies)?;
+        dict.set_item("errors", &self.errors)?;
+        Ok(dict)
+    }
+}
+
+fn log_buffer() -> &'static Mutex<VecDeque<AuditLogEntry>> {
+    static BUFFER: OnceLock<Mutex<VecDeque<AuditLogEntry>>> = OnceLock::new();
+    BUFFER.get_or_init(|| Mutex::new(VecDeque::with_capacity(MAX_LOG_ENTRIES)))
+}
+
+/// Record one authorization call, if logging is currently enabled
+/// (internal use, called from `is_authorized`/`is_authorized_partial`).
+pub(crate) fn record(
+    principal: Option<&str>,
+    action: Option<&str>,
+    resource: Option<&str>,
+    decision: Option<&str>,
+    determining_policies: &[String],
+    errors: &[String],
+) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let id = format!("log-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed));
+    let timestamp_unix_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    let entry = AuditLogEntry {
+        id,
+        timestamp_unix_ms,
+        principal: principal.map(str::to_string),
+        action: action.map(str::to_string),
+        resource: resource.map(str::to_string),
+        decision: decision.map(str::to_string),
+        determining_policies: determining_policies.to_vec(),
+        errors: errors.to_vec(),
+    };
+
+    let mut buf = log_buffer().lock().unwrap();
+    if buf.len() >= MAX_LOG_ENTRIES {
+        buf.pop_front();
+    }
+    buf.push_back(entry);
+}
+
+/// Opt-in audit log of authorization decisions.
+///
+/// Disabled by default. Once enabled (`audit_log.set_enabled(True)`), every
+/// `is_authorized`/`is_authorized_partial` call records a structured entry
+/// -- a generated id, timestamp, the principal/action/resource, the final
+/// decision, the determining policy ids, and any errors -- into a bounded
+/// in-memory ring buffer, so long-running services get decision auditing
+/// without wiring up their own instrumentation.
+///
+/// Example:
+///     >>> from cedar_py import audit_log
+///     >>> audit_log.set_enabled(True)
+///     >>> is_authorized(request, policies)
+///     >>> for entry in audit_log.pop_logs():
+///     ...     print(entry["decision"], entry["determining_policies"])
+#[pyclass(name = "AuditLogger")]
+pub struct AuditLogger;
+
+#[pymethods]
+impl AuditLogger {
+    /// Enable or disable audit logging.
+    ///
+    /// Args:
+    ///     enabled (bool): Whether subsequent authorization calls should be recorded
+    fn set_enabled(&self, enabled: bool) {
+        ENABLED.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether audit logging is currently enabled.
+    #[getter]
+    fn enabled(&self) -> bool {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// Drain and return every buffered log entry.
+    ///
+    /// Returns:
+    ///     list[dict]: The buffered entries, oldest first. The buffer is
+    ///     empty after this call.
+    fn pop_logs<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let mut buf = log_buffer().lock().unwrap();
+        let mut out = Vec::with_capacity(buf.len());
+        for entry in buf.drain(..) {
+            out.push(entry.to_pydict(py)?);
+        }
+        Ok(out)
+    }
+
+    /// Look up a single buffered entry by id, without draining the rest of
+    /// the buffer.
+    ///
+    /// Args:
+    ///     log_id (str): The entry's `"id"` (ids run "log-1", "log-2", ...
+    ///         in recording order)
+    ///
+    /// Returns:
+    ///     dict or None: The entry, or None if it's not (or no longer)
+    ///     buffered; entries already drained by `pop_logs` are not found
+    fn get_log_by_id<'py>(&self, py: Python<'py>, log_id: &str) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let buf = log_buffer().lock().unwrap();
+        match buf.iter().find(|entry| entry.id == log_id) {
+            Some(entry) => Ok(Some(entry.to_pydict(py)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AuditLogger(enabled={})", ENABLED.load(Ordering::Relaxed))
+    }
+}
@@ -40,12 +40,19 @@ pub fn py_to_json(obj: &Bound<'_, PyAny>) -> PyResult<JsonValue> {
     }
 }
 
-/// Convert a Python dict to a Cedar Context
-pub fn py_dict_to_context(dict: &Bound<'_, PyDict>) -> PyResult<Context> {
+/// Convert a Python dict to a list of (key, RestrictedExpression) pairs.
+///
+/// This is the shared building block behind `py_dict_to_context`, split out
+/// so callers that need to append extra pairs (e.g. unknowns for partial
+/// evaluation) before constructing the final `Context` don't have to
+/// re-implement the dict-to-JSON walk.
+pub fn py_dict_to_context_pairs(
+    dict: &Bound<'_, PyDict>,
+) -> PyResult<Vec<(String, RestrictedExpression)>> {
     // Convert Python dict to JSON
     let json_value = py_to_json(dict.as_any())?;
 
-    // Convert JSON object to a map of restricted expressions
+    // Convert JSON object to a list of restricted expression pairs
     if let JsonValue::Object(map) = json_value {
         let mut pairs = Vec::new();
 
@@ -55,14 +62,65 @@ pub fn py_dict_to_context(dict: &Bound<'_, PyDict>) -> PyResult<Context> {
             pairs.push((key, expr));
         }
 
-        // Create context from pairs
-        Context::from_pairs(pairs)
-            .map_err(|e| PyValueError::new_err(format!("Failed to create context: {}", e)))
+        Ok(pairs)
     } else {
         Err(PyValueError::new_err("Context must be a dictionary"))
     }
 }
 
+/// Convert a Python dict to a Cedar Context
+pub fn py_dict_to_context(dict: &Bound<'_, PyDict>) -> PyResult<Context> {
+    let pairs = py_dict_to_context_pairs(dict)?;
+
+    Context::from_pairs(pairs)
+        .map_err(|e| PyValueError::new_err(format!("Failed to create context: {}", e)))
+}
+
+/// Recognize Cedar's JSON extension-escape form, e.g.
+/// `{"__extn": {"fn": "decimal", "arg": "1.23"}}` or
+/// `{"fn": "ip", "arg": "10.0.0.0/24"}`, and build the matching
+/// `RestrictedExpression`. Returns `None` if `map` isn't shaped like an
+/// extension-function call, so the caller can fall back to treating it as a
+/// plain record.
+fn try_extension_expr(map: &serde_json::Map<String, JsonValue>) -> PyResult<Option<RestrictedExpression>> {
+    // Both `{"__extn": {"fn": ..., "arg": ...}}` and the bare
+    // `{"fn": ..., "arg": ...}` shape are accepted, mirroring Cedar's own
+    // JSON (de)serialization of extension values.
+    let extn = match map.get("__extn") {
+        Some(JsonValue::Object(extn)) if map.len() == 1 => extn,
+        Some(_) => return Ok(None),
+        None if map.len() == 2 && map.contains_key("fn") && map.contains_key("arg") => map,
+        None => return Ok(None),
+    };
+
+    let fn_name = match extn.get("fn") {
+        Some(JsonValue::String(s)) => s.as_str(),
+        _ => return Ok(None),
+    };
+    let arg = match extn.get("arg") {
+        Some(JsonValue::String(s)) => s.clone(),
+        _ => {
+            return Err(PyValueError::new_err(
+                "Extension value 'arg' must be a string",
+            ))
+        }
+    };
+
+    let expr = match fn_name {
+        "decimal" => RestrictedExpression::new_decimal(arg),
+        "ip" | "ipaddr" => RestrictedExpression::new_ip(arg),
+        "datetime" => RestrictedExpression::new_datetime(arg),
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported extension function '{}'",
+                other
+            )))
+        }
+    };
+
+    Ok(Some(expr))
+}
+
 /// Convert a JSON value to a RestrictedExpression
 pub fn json_value_to_restricted_expr(value: &JsonValue) -> PyResult<RestrictedExpression> {
     match value {
@@ -89,6 +147,10 @@ pub fn json_value_to_restricted_expr(value: &JsonValue) -> PyResult<RestrictedEx
             Ok(RestrictedExpression::new_set(exprs))
         }
         JsonValue::Object(map) => {
+            if let Some(expr) = try_extension_expr(map)? {
+                return Ok(expr);
+            }
+
             let mut pairs = Vec::new();
             for (k, v) in map {
                 pairs.push((k.clone(), json_value_to_restricted_expr(v)?));
@@ -1,6 +1,45 @@
 use cedar_policy::{Decision as CedarDecision, Response as CedarResponse};
 use pyo3::prelude::*;
 
+/// A per-policy evaluation error attached to a `Decision`.
+#[pyclass]
+#[derive(Clone)]
+pub struct DecisionError {
+    policy_id: Option<String>,
+    message: String,
+}
+
+#[pymethods]
+impl DecisionError {
+    /// The id of the policy that raised the error, if it could be attributed
+    /// to one (some errors, e.g. missing entities, aren't policy-specific).
+    #[getter]
+    pub(crate) fn policy_id(&self) -> Option<String> {
+        self.policy_id.clone()
+    }
+
+    /// The human-readable error message.
+    #[getter]
+    pub(crate) fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DecisionError(policy_id={:?}, message='{}')",
+            self.policy_id, self.message
+        )
+    }
+}
+
+impl DecisionError {
+    /// Construct a `DecisionError` (internal use, shared by `Decision` and
+    /// `PartialDecision`'s concrete-resolution path).
+    pub(crate) fn new(policy_id: Option<String>, message: String) -> Self {
+        DecisionError { policy_id, message }
+    }
+}
+
 /// Authorization decision result.
 ///
 /// This represents the result of an authorization decision, including
@@ -8,6 +47,8 @@ use pyo3::prelude::*;
 #[pyclass]
 pub struct Decision {
     decision: String,
+    determining_policies: Vec<String>,
+    errors: Vec<DecisionError>,
     diagnostics: Vec<String>,
 }
 
@@ -19,7 +60,29 @@ impl Decision {
         self.decision.clone()
     }
 
+    /// Get the IDs of the policies that determined this decision.
+    ///
+    /// Returns:
+    ///     list[str]: Contributing policy IDs, e.g. for an audit log entry
+    ///     like "access granted by policy `allow-admins`"
+    #[getter]
+    pub(crate) fn determining_policies(&self) -> Vec<String> {
+        self.determining_policies.clone()
+    }
+
+    /// Get the structured per-policy evaluation errors.
+    ///
+    /// Returns:
+    ///     list[DecisionError]: One entry per policy that failed to evaluate
+    #[getter]
+    pub(crate) fn errors(&self) -> Vec<DecisionError> {
+        self.errors.clone()
+    }
+
     /// Get the list of diagnostic messages.
+    ///
+    /// Kept for backward compatibility; sourced from `determining_policies`
+    /// and `errors` rather than string-parsed directly from Cedar.
     #[getter]
     fn diagnostics(&self) -> Vec<String> {
         self.diagnostics.clone()
@@ -56,21 +119,37 @@ impl Decision {
         }
         .to_string();
 
-        let mut diagnostics = Vec::new();
+        let determining_policies: Vec<String> = response
+            .diagnostics()
+            .reason()
+            .map(|id| id.to_string())
+            .collect();
 
-        // Add information about errors if any
-        for error in response.diagnostics().errors() {
-            diagnostics.push(format!("Error: {}", error));
-        }
+        let errors: Vec<DecisionError> = response
+            .diagnostics()
+            .errors()
+            .map(|error| DecisionError::new(error.policy_id().map(|id| id.to_string()), error.to_string()))
+            .collect();
 
-        // Add information about reasons (policies that contributed to the decision)
-        for reason in response.diagnostics().reason() {
-            diagnostics.push(format!("Reason: {}", reason));
+        let mut diagnostics = Vec::new();
+        for error in &errors {
+            diagnostics.push(format!("Error: {}", error.message));
+        }
+        for policy_id in &determining_policies {
+            diagnostics.push(format!("Reason: {}", policy_id));
         }
 
         Decision {
             decision,
+            determining_policies,
+            errors,
             diagnostics,
         }
     }
+
+    /// The decision as a string, 'Allow' or 'Deny' (internal use, for the
+    /// audit log).
+    pub(crate) fn decision_str(&self) -> &str {
+        &self.decision
+    }
 }
@@ -1,10 +1,11 @@
-use cedar_policy::{Entities, Entity, EntityUid};
+use cedar_policy::{Entities, Entity, EntityUid, Schema};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 use std::collections::HashMap;
 use std::str::FromStr;
 use crate::context_utils::py_to_json;
+use crate::schema::CedarSchema;
 
 /// An entity store for Cedar authorization.
 ///
@@ -83,6 +84,43 @@ impl EntityStore {
         Ok(())
     }
 
+    /// Bulk-load an entire entity graph from Cedar's JSON entity format.
+    ///
+    /// Lets users load everything produced by other Cedar tooling in one
+    /// call instead of looping over `add_entity`.
+    ///
+    /// Args:
+    ///     json_str (str): The JSON-encoded entities (a list of entity objects)
+    ///     schema (CedarSchema, optional): If given, attribute types are
+    ///         validated and the entity hierarchy (e.g. action group
+    ///         membership) is auto-completed against it
+    ///
+    /// Returns:
+    ///     EntityStore: A new EntityStore containing the parsed entities
+    ///
+    /// Raises:
+    ///     ValueError: If the JSON is invalid, or (with a schema) doesn't validate
+    ///
+    /// Example:
+    ///     >>> store = EntityStore.from_json(open("entities.json").read(), schema=schema)
+    #[classmethod]
+    #[pyo3(signature = (json_str, schema=None))]
+    fn from_json(
+        _cls: &Bound<'_, pyo3::types::PyType>,
+        json_str: &str,
+        schema: Option<&CedarSchema>,
+    ) -> PyResult<Self> {
+        let cedar_entities = Entities::from_json_str(json_str, schema.map(|s| s.get_schema()))
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse entities JSON: {}", e)))?;
+
+        let mut entities = HashMap::new();
+        for entity in cedar_entities.iter() {
+            entities.insert(entity.uid().to_string(), entity.clone());
+        }
+
+        Ok(EntityStore { entities })
+    }
+
     /// Get the number of entities in the store.
     fn __len__(&self) -> usize {
         self.entities.len()
@@ -101,9 +139,13 @@ impl EntityStore {
 
 impl EntityStore {
     /// Convert to Cedar Entities (internal use).
-    pub(crate) fn to_cedar_entities(&self) -> PyResult<Entities> {
+    ///
+    /// When `schema` is given, attribute types are validated and the entity
+    /// hierarchy (e.g. action group membership) is auto-completed against
+    /// it rather than trusting the store's entities as-is.
+    pub(crate) fn to_cedar_entities(&self, schema: Option<&Schema>) -> PyResult<Entities> {
         let entities_vec: Vec<Entity> = self.entities.values().cloned().collect();
-        Entities::from_entities(entities_vec, None)
+        Entities::from_entities(entities_vec, schema)
             .map_err(|e| PyValueError::new_err(format!("Failed to create entity collection: {}", e)))
     }
 }
@@ -0,0 +1,30 @@
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::path::Path;
+
+/// Spawn a background `notify` watcher over `path` that calls `on_change`
+/// whenever a file under it is modified, created, or removed.
+///
+/// Shared by `PolicySet::start_watching` and `PolicyStore::start_watching`,
+/// which otherwise differ only in how they reload and where they stash the
+/// reloaded policy set.
+pub(crate) fn watch_for_changes<F>(path: &Path, mut on_change: F) -> PyResult<RecommendedWatcher>
+where
+    F: FnMut() + Send + 'static,
+{
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let Ok(event) = res else { return };
+        if !(event.kind.is_modify() || event.kind.is_create() || event.kind.is_remove()) {
+            return;
+        }
+        on_change();
+    })
+    .map_err(|e| PyValueError::new_err(format!("Failed to start policy watcher: {}", e)))?;
+
+    watcher
+        .watch(path, RecursiveMode::NonRecursive)
+        .map_err(|e| PyValueError::new_err(format!("Failed to watch '{}': {}", path.display(), e)))?;
+
+    Ok(watcher)
+}
@@ -3,21 +3,55 @@ use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use std::str::FromStr;
 
+mod audit_log;
 mod context_utils;
 mod decision;
 mod entity_store;
+mod fs_watch;
+mod partial_decision;
 mod policy_set;
+mod policy_store;
 mod policy_template;
 mod request;
 mod schema;
 
-use decision::Decision;
+use cedar_policy::PolicySet as CedarPolicySet;
+use decision::{Decision, DecisionError};
 use entity_store::EntityStore;
+use partial_decision::PartialDecision;
 use policy_set::PolicySet;
+use policy_store::PolicyStore;
 use policy_template::PolicyTemplate;
 use request::Request;
 use schema::CedarSchema;
 
+/// Anything `is_authorized`/`is_authorized_partial` can evaluate against: a
+/// `PolicySet` snapshot, or a `PolicyStore` backed by files on disk that may
+/// be reloaded (manually or by a background watcher) between calls.
+#[derive(FromPyObject)]
+enum PolicyProvider<'py> {
+    Set(PyRef<'py, PolicySet>),
+    Store(PyRef<'py, PolicyStore>),
+}
+
+impl PolicyProvider<'_> {
+    fn get_cedar_policy_set(&self) -> CedarPolicySet {
+        match self {
+            PolicyProvider::Set(set) => set.get_cedar_policy_set(),
+            PolicyProvider::Store(store) => store.get_cedar_policy_set(),
+        }
+    }
+
+    /// The schema a `PolicyStore` was constructed with, if any. `PolicySet`
+    /// carries no schema of its own, so this is always `None` for it.
+    fn schema(&self) -> Option<&cedar_policy::Schema> {
+        match self {
+            PolicyProvider::Set(_) => None,
+            PolicyProvider::Store(store) => store.schema(),
+        }
+    }
+}
+
 /// Validate a Cedar policy text.
 ///
 /// Args:
@@ -58,7 +92,9 @@ fn validate_template(template_text: &str) -> PyResult<bool> {
 ///
 /// Args:
 ///     request (Request): The authorization request
-///     policies (PolicySet): The policy set to evaluate against
+///     policies (PolicySet | PolicyStore): The policy set to evaluate
+///         against. Pass a `PolicyStore` so a long-lived service always
+///         authorizes against the latest on-disk policies.
 ///     entities (EntityStore, optional): Optional entity store for hierarchical policies
 ///
 /// Returns:
@@ -72,7 +108,7 @@ fn validate_template(template_text: &str) -> PyResult<bool> {
 #[pyo3(signature = (request, policies, entities=None))]
 fn is_authorized(
     request: &Request,
-    policies: &PolicySet,
+    policies: PolicyProvider,
     entities: Option<&EntityStore>,
 ) -> PyResult<Decision> {
     // Create the authorizer
@@ -84,9 +120,13 @@ fn is_authorized(
     // Get the Cedar policy set
     let policy_set = policies.get_cedar_policy_set();
 
+    // A PolicyStore's schema (if any) is a fallback for a request that
+    // doesn't carry its own.
+    let schema = request.schema().or_else(|| policies.schema());
+
     // Get entities or use empty set
     let cedar_entities = if let Some(store) = entities {
-        store.to_cedar_entities()?
+        store.to_cedar_entities(schema)?
     } else {
         cedar_policy::Entities::empty()
     };
@@ -94,21 +134,126 @@ fn is_authorized(
     // Make the authorization decision
     let response = authorizer.is_authorized(&cedar_request, &policy_set, &cedar_entities);
 
-    Ok(Decision::from_cedar_response(response))
+    let decision = Decision::from_cedar_response(response);
+    audit_log::record(
+        request.principal(),
+        request.action(),
+        request.resource(),
+        Some(decision.decision_str()),
+        &decision.determining_policies(),
+        &decision_error_strings(&decision.errors()),
+    );
+
+    Ok(decision)
+}
+
+/// Render a `Decision`/`PartialDecision`'s structured errors as
+/// "policy_id: message" strings for the audit log, which stores everything
+/// as plain Python-friendly values rather than nested objects.
+fn decision_error_strings(errors: &[DecisionError]) -> Vec<String> {
+    errors
+        .iter()
+        .map(|e| match e.policy_id() {
+            Some(policy_id) => format!("{}: {}", policy_id, e.message()),
+            None => e.message(),
+        })
+        .collect()
+}
+
+/// Make a partial authorization decision over a request with unknown parts.
+///
+/// Unlike `is_authorized`, this does not require `principal`/`resource`/
+/// context values to all be known. Where `request` leaves a slot unknown,
+/// the authorizer folds away everything it can determine and returns the
+/// remaining *residual* policies instead of failing or guessing.
+///
+/// Args:
+///     request (Request): The authorization request, with some parts
+///         possibly left unknown (see `Request`)
+///     policies (PolicySet | PolicyStore): The policy set to evaluate
+///         against. Pass a `PolicyStore` so a long-lived service always
+///         authorizes against the latest on-disk policies.
+///     entities (EntityStore, optional): Optional entity store for hierarchical policies
+///
+/// Returns:
+///     PartialDecision: Either a concrete decision, or the residual
+///     policies that still apply to the unknowns
+///
+/// Example:
+///     >>> # "Which documents can alice view?"
+///     >>> req = Request(principal='User::"alice"', action='Action::"view"', resource=None)
+///     >>> partial = is_authorized_partial(req, policies, store)
+///     >>> for candidate in documents:
+///     ...     if partial.reauthorize({"resource": candidate}):
+///     ...         print(f"alice can view {candidate}")
+#[pyfunction]
+#[pyo3(signature = (request, policies, entities=None))]
+fn is_authorized_partial(
+    request: &Request,
+    policies: PolicyProvider,
+    entities: Option<&EntityStore>,
+) -> PyResult<PartialDecision> {
+    let authorizer = Authorizer::new();
+
+    let cedar_request = request.to_cedar_partial_request()?;
+    let policy_set = policies.get_cedar_policy_set();
+    let schema = request.schema().or_else(|| policies.schema());
+
+    let cedar_entities = if let Some(store) = entities {
+        store.to_cedar_entities(schema)?
+    } else {
+        cedar_policy::Entities::empty()
+    };
+
+    let response = authorizer.is_authorized_partial(&cedar_request, &policy_set, &cedar_entities);
+
+    let partial = PartialDecision::from_partial_response(response, request, policy_set, cedar_entities);
+
+    // While residual, there's no concrete decision or error set yet; log the
+    // still-applicable policy ids in their place so the entry isn't empty.
+    let logged_policies = if partial.is_residual() {
+        partial
+            .residual_policies()
+            .into_iter()
+            .map(|(policy_id, _)| policy_id)
+            .collect::<Vec<_>>()
+    } else {
+        partial.determining_policies()
+    };
+
+    audit_log::record(
+        request.principal(),
+        request.action(),
+        request.resource(),
+        partial.decision().as_deref(),
+        &logged_policies,
+        &decision_error_strings(&partial.errors()),
+    );
+
+    Ok(partial)
 }
 
 /// Python bindings for the Cedar policy language.
 #[pymodule]
 fn _cedar_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PolicySet>()?;
+    m.add_class::<PolicyStore>()?;
     m.add_class::<PolicyTemplate>()?;
     m.add_class::<Request>()?;
     m.add_class::<Decision>()?;
+    m.add_class::<DecisionError>()?;
+    m.add_class::<PartialDecision>()?;
     m.add_class::<EntityStore>()?;
     m.add_class::<CedarSchema>()?;
+    m.add_class::<schema::ValidationResult>()?;
+    m.add_class::<schema::ValidationDiagnostic>()?;
+    m.add_class::<schema::SourceSpan>()?;
     m.add_function(wrap_pyfunction!(validate_policy, m)?)?;
     m.add_function(wrap_pyfunction!(validate_template, m)?)?;
     m.add_function(wrap_pyfunction!(schema::validate_policies, m)?)?;
     m.add_function(wrap_pyfunction!(is_authorized, m)?)?;
+    m.add_function(wrap_pyfunction!(is_authorized_partial, m)?)?;
+    m.add_class::<audit_log::AuditLogger>()?;
+    m.add("audit_log", Py::new(m.py(), audit_log::AuditLogger)?)?;
     Ok(())
 }
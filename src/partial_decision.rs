@@ -0,0 +1,236 @@
+use crate::context_utils::py_to_json;
+use crate::request::Request;
+use cedar_policy::{
+    Authorizer, Context, Decision as CedarDecision, Entities, EntityUid, PartialResponse,
+    PolicySet as CedarPolicySet, Request as CedarRequest, RestrictedExpression, Schema,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::str::FromStr;
+
+use crate::decision::{Decision, DecisionError};
+
+/// Result of a partial authorization evaluation.
+///
+/// When part of the request is left unknown (see `Request`), the authorizer
+/// may not be able to reach a concrete Allow/Deny decision. In that case the
+/// still-applicable policies are returned as *residuals*: the known parts
+/// of the request are folded away and the unknown parts remain symbolic.
+/// Once more information is known, pass it to `reauthorize` to narrow the
+/// residuals down to a concrete `Decision`.
+#[pyclass]
+pub struct PartialDecision {
+    decision: Option<String>,
+    residual_policies: Vec<(String, String)>,
+    // Populated only when `decision` resolved concretely; mirrors
+    // `Decision::from_cedar_response` so audit logging doesn't lose them.
+    determining_policies: Vec<String>,
+    errors: Vec<DecisionError>,
+    // Retained so `reauthorize` can re-run evaluation with a narrower request
+    // without the caller having to hold on to the original policies/entities.
+    policy_set: CedarPolicySet,
+    entities: Entities,
+    schema: Option<Schema>,
+    principal: Option<String>,
+    action: Option<String>,
+    resource: Option<String>,
+    context_pairs: Vec<(String, RestrictedExpression)>,
+    unknown_context_keys: Vec<String>,
+}
+
+#[pymethods]
+impl PartialDecision {
+    /// Get the concrete decision, if one could be reached.
+    ///
+    /// Returns:
+    ///     str or None: 'Allow'/'Deny' if the request was fully resolved,
+    ///     None if the decision is still residual.
+    #[getter]
+    pub(crate) fn decision(&self) -> Option<String> {
+        self.decision.clone()
+    }
+
+    /// Get the non-trivial residual policies that still apply.
+    ///
+    /// Returns:
+    ///     list[tuple[str, str]]: (policy_id, policy_text) pairs for each
+    ///     policy whose applicability could not be fully resolved against
+    ///     the unknowns.
+    #[getter]
+    pub(crate) fn residual_policies(&self) -> Vec<(String, String)> {
+        self.residual_policies.clone()
+    }
+
+    /// Get the IDs of the policies that determined this decision, if it
+    /// resolved concretely.
+    ///
+    /// Returns:
+    ///     list[str]: Contributing policy IDs, empty while `decision` is None
+    #[getter]
+    pub(crate) fn determining_policies(&self) -> Vec<String> {
+        self.determining_policies.clone()
+    }
+
+    /// Get the structured per-policy evaluation errors, if the decision
+    /// resolved concretely.
+    ///
+    /// Returns:
+    ///     list[DecisionError]: One entry per policy that failed to evaluate,
+    ///     empty while `decision` is None
+    #[getter]
+    pub(crate) fn errors(&self) -> Vec<DecisionError> {
+        self.errors.clone()
+    }
+
+    /// Check whether the decision is still residual (not yet concrete).
+    ///
+    /// Returns:
+    ///     bool: True if `decision` is None
+    pub(crate) fn is_residual(&self) -> bool {
+        self.decision.is_none()
+    }
+
+    /// Narrow the residuals down using newly-known values and re-authorize.
+    ///
+    /// Args:
+    ///     now_known (dict): Maps "principal"/"action"/"resource" to their
+    ///         now-known entity UID, and/or previously-unknown context key
+    ///         names to their now-known values.
+    ///
+    /// Returns:
+    ///     Decision: The concrete decision once the supplied values resolve
+    ///     every remaining unknown.
+    ///
+    /// Raises:
+    ///     ValueError: If unknowns remain after applying `now_known`.
+    ///
+    /// Example:
+    ///     >>> partial = is_authorized_partial(request, policies, store)
+    ///     >>> decision = partial.reauthorize({"resource": 'Document::"report"'})
+    fn reauthorize(&self, now_known: &Bound<'_, PyDict>) -> PyResult<Decision> {
+        let mut principal = self.principal.clone();
+        let mut action = self.action.clone();
+        let mut resource = self.resource.clone();
+        let mut context_pairs = self.context_pairs.clone();
+        let mut unknown_context_keys = Vec::new();
+
+        for key in &self.unknown_context_keys {
+            if let Some(value) = now_known.get_item(key)? {
+                let json_val = py_to_json(&value)?;
+                let expr = crate::context_utils::json_value_to_restricted_expr(&json_val)?;
+                context_pairs.push((key.clone(), expr));
+            } else {
+                unknown_context_keys.push(key.clone());
+            }
+        }
+
+        if let Some(value) = now_known.get_item("principal")? {
+            principal = Some(value.extract()?);
+        }
+        if let Some(value) = now_known.get_item("action")? {
+            action = Some(value.extract()?);
+        }
+        if let Some(value) = now_known.get_item("resource")? {
+            resource = Some(value.extract()?);
+        }
+
+        if principal.is_some()
+            && resource.is_some()
+            && action.is_some()
+            && unknown_context_keys.is_empty()
+        {
+            let parse = |field: &str, value: &str| {
+                EntityUid::from_str(value)
+                    .map_err(|e| PyValueError::new_err(format!("Invalid {}: {}", field, e)))
+            };
+
+            let principal_uid = parse("principal", principal.as_deref().unwrap())?;
+            let action_uid = parse("action", action.as_deref().unwrap())?;
+            let resource_uid = parse("resource", resource.as_deref().unwrap())?;
+            let context = Context::from_pairs(context_pairs)
+                .map_err(|e| PyValueError::new_err(format!("Failed to create context: {}", e)))?;
+
+            let cedar_request = CedarRequest::new(
+                principal_uid,
+                action_uid,
+                resource_uid,
+                context,
+                self.schema.as_ref(),
+            )
+            .map_err(|e| PyValueError::new_err(format!("Failed to create request: {}", e)))?;
+
+            let authorizer = Authorizer::new();
+            let response =
+                authorizer.is_authorized(&cedar_request, &self.policy_set, &self.entities);
+            return Ok(Decision::from_cedar_response(response));
+        }
+
+        Err(PyValueError::new_err(
+            "reauthorize did not resolve to a concrete decision; more values are still unknown",
+        ))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PartialDecision(decision={:?}, residual_policies={})",
+            self.decision,
+            self.residual_policies.len()
+        )
+    }
+}
+
+impl PartialDecision {
+    pub(crate) fn from_partial_response(
+        response: PartialResponse,
+        request: &Request,
+        policy_set: CedarPolicySet,
+        entities: Entities,
+    ) -> Self {
+        let (decision, residual_policies, determining_policies, errors) = match &response {
+            PartialResponse::Concrete(resp) => {
+                let decision = match resp.decision() {
+                    CedarDecision::Allow => "Allow",
+                    CedarDecision::Deny => "Deny",
+                }
+                .to_string();
+
+                let determining_policies: Vec<String> = resp
+                    .diagnostics()
+                    .reason()
+                    .map(|id| id.to_string())
+                    .collect();
+
+                let errors: Vec<DecisionError> = resp
+                    .diagnostics()
+                    .errors()
+                    .map(|error| DecisionError::new(error.policy_id().map(|id| id.to_string()), error.to_string()))
+                    .collect();
+
+                (Some(decision), Vec::new(), determining_policies, errors)
+            }
+            PartialResponse::Residual(residual) => {
+                let residual_policies = residual
+                    .nontrivial_residuals()
+                    .map(|policy| (policy.id().to_string(), policy.to_string()))
+                    .collect();
+                (None, residual_policies, Vec::new(), Vec::new())
+            }
+        };
+
+        PartialDecision {
+            decision,
+            residual_policies,
+            determining_policies,
+            errors,
+            policy_set,
+            entities,
+            schema: request.schema().cloned(),
+            principal: request.principal().map(str::to_string),
+            action: request.action().map(str::to_string),
+            resource: request.resource().map(str::to_string),
+            context_pairs: request.context_pairs().to_vec(),
+            unknown_context_keys: request.unknown_context_keys().to_vec(),
+        }
+    }
+}
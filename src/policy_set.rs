@@ -1,23 +1,36 @@
-use cedar_policy::{EntityUid, Policy, PolicyId, PolicySet as CedarPolicySet, SlotId};
+use cedar_policy::{EntityUid, Policy, PolicyId, PolicySet as CedarPolicySet, SlotId, Template};
+use notify::RecommendedWatcher;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 use crate::policy_template::PolicyTemplate;
+use crate::schema::CedarSchema;
 
 /// A collection of Cedar policies and policy templates.
 ///
-/// This class represents a set of Cedar policies that can be evaluated
-/// together for authorization decisions. It supports both regular policies
-/// and template-linked policies (policies instantiated from templates).
+/// This class wraps a live `cedar_policy::PolicySet`, so policies and
+/// templates are parsed once (at `add`/`add_template`/`link` time) rather
+/// than being re-parsed from concatenated text on every authorization call,
+/// and the IDs you give them are the IDs Cedar actually uses.
+///
+/// When built via `from_directory`, the set can also be reloaded (manually
+/// via `reload`, or automatically via a background `watch=True` filesystem
+/// watcher) so long-running services can pick up policy edits without a
+/// restart.
 #[pyclass]
 pub struct PolicySet {
-    policies: HashMap<String, String>, // Store policy text instead of parsed Policy
-    templates: HashMap<String, String>, // Store template text
-    template_links: HashMap<String, (String, HashMap<String, String>)>, // policy_id -> (template_id, slots)
+    inner: Arc<Mutex<CedarPolicySet>>,
     next_auto_id: usize, // Track next available auto-generated ID
+    directory: Option<PathBuf>,
+    last_reload_error: Arc<Mutex<Option<String>>>,
+    // Kept alive only to keep the background filesystem watcher running;
+    // never read.
+    _watcher: Mutex<Option<RecommendedWatcher>>,
 }
 
 #[pymethods]
@@ -26,10 +39,11 @@ impl PolicySet {
     #[new]
     fn new() -> Self {
         PolicySet {
-            policies: HashMap::new(),
-            templates: HashMap::new(),
-            template_links: HashMap::new(),
+            inner: Arc::new(Mutex::new(CedarPolicySet::new())),
             next_auto_id: 0,
+            directory: None,
+            last_reload_error: Arc::new(Mutex::new(None)),
+            _watcher: Mutex::new(None),
         }
     }
 
@@ -54,27 +68,151 @@ impl PolicySet {
     ///     ... ''')
     #[classmethod]
     fn from_str(_cls: &Bound<'_, pyo3::types::PyType>, policies_text: &str) -> PyResult<Self> {
-        // Parse the policy set to validate and extract individual policies
-        let policy_set = CedarPolicySet::from_str(policies_text)
+        let inner = CedarPolicySet::from_str(policies_text)
             .map_err(|e| PyValueError::new_err(format!("Invalid policy set: {}", e)))?;
+        let next_auto_id = inner.policies().count();
 
-        let mut policies_map = HashMap::new();
+        Ok(PolicySet {
+            inner: Arc::new(Mutex::new(inner)),
+            next_auto_id,
+            directory: None,
+            last_reload_error: Arc::new(Mutex::new(None)),
+            _watcher: Mutex::new(None),
+        })
+    }
 
-        // Cedar assigns auto IDs like "policy0", "policy1", etc.
-        // We need to extract each policy and store it with its ID
-        for policy in policy_set.policies() {
-            let policy_id = policy.id().to_string();
-            let policy_text = policy.to_string();
+    /// Load every `*.cedar` policy file (and `*.cedartemplate` template file)
+    /// in a directory into one PolicySet, keyed by filename-derived IDs.
+    ///
+    /// Args:
+    ///     path (str): Directory containing `.cedar`/`.cedartemplate` files
+    ///     watch (bool, optional): If True, spawn a background filesystem
+    ///         watcher that reloads the set whenever a file under `path`
+    ///         changes (default: False)
+    ///
+    /// Returns:
+    ///     PolicySet: A new PolicySet instance loaded from the directory
+    ///
+    /// Raises:
+    ///     ValueError: If the directory can't be read, or any file in it
+    ///         fails to parse
+    ///
+    /// Example:
+    ///     >>> policies = PolicySet.from_directory("./policies", watch=True)
+    #[classmethod]
+    #[pyo3(signature = (path, watch=false))]
+    fn from_directory(
+        _cls: &Bound<'_, pyo3::types::PyType>,
+        path: String,
+        watch: bool,
+    ) -> PyResult<Self> {
+        let dir = PathBuf::from(&path);
+        let loaded = Self::load_from_directory(&dir)
+            .map_err(|e| PyValueError::new_err(format!("Failed to load '{}': {}", path, e)))?;
+
+        let mut policy_set = PolicySet {
+            inner: Arc::new(Mutex::new(loaded)),
+            next_auto_id: 0,
+            directory: Some(dir),
+            last_reload_error: Arc::new(Mutex::new(None)),
+            _watcher: Mutex::new(None),
+        };
 
-            policies_map.insert(policy_id, policy_text);
+        if watch {
+            policy_set.start_watching()?;
         }
 
-        Ok(PolicySet {
-            policies: policies_map.clone(),
-            templates: HashMap::new(),
-            template_links: HashMap::new(),
-            next_auto_id: policies_map.len(),
-        })
+        Ok(policy_set)
+    }
+
+    /// Build a PolicySet from a map of policy JSON (EST) objects.
+    ///
+    /// Mirrors `from_str`/`from_directory`, but for policies persisted as
+    /// structured JSON (e.g. in a database, or built programmatically by a
+    /// UI) rather than Cedar text.
+    ///
+    /// Args:
+    ///     policies (dict[str, str]): Maps policy id to its JSON-encoded
+    ///         (EST) policy text
+    ///
+    /// Returns:
+    ///     PolicySet: A new PolicySet instance with the parsed policies
+    ///
+    /// Raises:
+    ///     ValueError: If any entry's JSON is invalid
+    ///
+    /// Example:
+    ///     >>> policies = PolicySet.from_json({"p0": other.get_policy_json("p0")})
+    #[classmethod]
+    fn from_json(_cls: &Bound<'_, pyo3::types::PyType>, policies: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let mut policy_set = PolicySet::new();
+
+        for (key, value) in policies.iter() {
+            let policy_id: String = key.extract()?;
+            let policy_json: String = value.extract()?;
+            policy_set.add_policy_json(policy_id, &policy_json)?;
+        }
+
+        Ok(policy_set)
+    }
+
+    /// Validate this policy set against a schema.
+    ///
+    /// Args:
+    ///     schema (CedarSchema): The schema to validate against
+    ///     mode (str, optional): Validation mode - "strict" or "permissive" (default: "strict")
+    ///
+    /// Returns:
+    ///     ValidationResult: Whether validation passed, plus any errors/warnings
+    ///
+    /// Example:
+    ///     >>> result = policies.validate(schema)
+    ///     >>> if not result.passed:
+    ///     ...     print(result.errors)
+    #[pyo3(signature = (schema, mode="strict"))]
+    fn validate(&self, schema: &CedarSchema, mode: &str) -> PyResult<crate::schema::ValidationResult> {
+        crate::schema::ValidationResult::from_validation(&self.get_cedar_policy_set(), schema, mode)
+    }
+
+    /// Reload the policy set from the directory it was loaded from.
+    ///
+    /// If the files on disk fail to parse or validate, the previous
+    /// in-force policy set is left untouched and the failure is recorded in
+    /// `last_reload_error` (and raised here).
+    ///
+    /// Raises:
+    ///     ValueError: If this set wasn't loaded via `from_directory`, or
+    ///         the reload fails
+    fn reload(&mut self) -> PyResult<()> {
+        let dir = self
+            .directory
+            .clone()
+            .ok_or_else(|| PyValueError::new_err("PolicySet was not loaded from a directory"))?;
+
+        match Self::load_from_directory(&dir) {
+            Ok(reloaded) => {
+                *self.inner.lock().unwrap() = reloaded;
+                *self.last_reload_error.lock().unwrap() = None;
+                Ok(())
+            }
+            Err(e) => {
+                *self.last_reload_error.lock().unwrap() = Some(e.clone());
+                Err(PyValueError::new_err(format!(
+                    "Failed to reload policies: {}",
+                    e
+                )))
+            }
+        }
+    }
+
+    /// The most recent reload failure, if any.
+    ///
+    /// Returns:
+    ///     str or None: The error from the last failed `reload()` (manual or
+    ///     watcher-triggered), or None if the last reload succeeded
+    #[getter]
+    fn last_reload_error(&self) -> Option<String> {
+        self.last_reload_error.lock().unwrap().clone()
     }
 
     /// Add a policy to the set.
@@ -84,15 +222,20 @@ impl PolicySet {
     ///     policy_text (str): The Cedar policy text
     ///
     /// Raises:
-    ///     ValueError: If the policy text is invalid
+    ///     ValueError: If the policy text is invalid, or `policy_id` is already in use
     fn add_policy(&mut self, policy_id: String, policy_text: &str) -> PyResult<()> {
-        // Validate the policy by parsing it
-        Policy::from_str(policy_text)
-            .map_err(|e| PyValueError::new_err(format!("Invalid policy: {}", e)))?;
-
-        // Store the original text
-        self.policies.insert(policy_id, policy_text.to_string());
-        Ok(())
+        let pid = PolicyId::from_str(&policy_id)
+            .map_err(|e| PyValueError::new_err(format!("Invalid policy id '{}': {}", policy_id, e)))?;
+
+        let policy = Policy::from_str(policy_text)
+            .map_err(|e| PyValueError::new_err(format!("Invalid policy: {}", e)))?
+            .new_id(pid);
+
+        self.inner
+            .lock()
+            .unwrap()
+            .add(policy)
+            .map_err(|e| PyValueError::new_err(format!("Failed to add policy '{}': {}", policy_id, e)))
     }
 
     /// Add multiple policies from a single text string to this PolicySet.
@@ -117,26 +260,29 @@ impl PolicySet {
     ///     ... ''')
     ///     >>> print(policy_ids)  # ['policy0', 'policy1']
     fn add_policies_from_str(&mut self, policies_text: &str) -> PyResult<Vec<String>> {
-        // Parse the policy set to validate and extract individual policies
-        let policy_set = CedarPolicySet::from_str(policies_text)
+        let parsed = CedarPolicySet::from_str(policies_text)
             .map_err(|e| PyValueError::new_err(format!("Invalid policy set: {}", e)))?;
 
         let mut added_ids = Vec::new();
+        let mut inner = self.inner.lock().unwrap();
 
-        // Assign unique IDs to avoid collisions with existing policies
-        for policy in policy_set.policies() {
-            let policy_text = policy.to_string();
-
-            // Generate unique ID using our counter
+        for policy in parsed.policies() {
             let unique_id = format!("policy{}", self.next_auto_id);
             self.next_auto_id += 1;
 
-            self.policies.insert(unique_id.clone(), policy_text);
+            let pid = PolicyId::from_str(&unique_id)
+                .map_err(|e| PyValueError::new_err(format!("Invalid policy id '{}': {}", unique_id, e)))?;
+            let renamed = policy.clone().new_id(pid);
+
+            inner.add(renamed).map_err(|e| {
+                PyValueError::new_err(format!("Failed to add policy '{}': {}", unique_id, e))
+            })?;
             added_ids.push(unique_id);
         }
 
         Ok(added_ids)
     }
+
     /// Get a policy by its ID.
     ///
     /// Args:
@@ -145,7 +291,77 @@ impl PolicySet {
     /// Returns:
     ///     str or None: The policy text, or None if not found
     fn get_policy(&self, policy_id: &str) -> Option<String> {
-        self.policies.get(policy_id).cloned()
+        let pid = PolicyId::from_str(policy_id).ok()?;
+        self.inner.lock().unwrap().policy(&pid).map(|p| p.to_string())
+    }
+
+    /// Export a policy to its canonical JSON (EST) representation.
+    ///
+    /// Args:
+    ///     policy_id (str): The policy identifier
+    ///
+    /// Returns:
+    ///     str or None: The JSON-encoded policy, or None if not found
+    ///
+    /// Raises:
+    ///     ValueError: If the policy can't be serialized to JSON
+    fn get_policy_json(&self, policy_id: &str) -> PyResult<Option<String>> {
+        let Some(pid) = PolicyId::from_str(policy_id).ok() else {
+            return Ok(None);
+        };
+        let Some(policy) = self.inner.lock().unwrap().policy(&pid).cloned() else {
+            return Ok(None);
+        };
+
+        let json_value = policy
+            .to_json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to serialize policy '{}': {}", policy_id, e)))?;
+
+        serde_json::to_string(&json_value)
+            .map(Some)
+            .map_err(|e| PyValueError::new_err(format!("Failed to serialize policy '{}': {}", policy_id, e)))
+    }
+
+    /// Add a policy to the set from its JSON (EST) representation.
+    ///
+    /// Args:
+    ///     policy_id (str): Unique identifier for the policy
+    ///     policy_json (str): The JSON-encoded policy (EST form)
+    ///
+    /// Raises:
+    ///     ValueError: If the JSON is invalid, or `policy_id` is already in use
+    fn add_policy_json(&mut self, policy_id: String, policy_json: &str) -> PyResult<()> {
+        let json_value: serde_json::Value = serde_json::from_str(policy_json)
+            .map_err(|e| PyValueError::new_err(format!("Invalid policy JSON: {}", e)))?;
+
+        let pid = PolicyId::from_str(&policy_id)
+            .map_err(|e| PyValueError::new_err(format!("Invalid policy id '{}': {}", policy_id, e)))?;
+
+        let policy = Policy::from_json(Some(pid), json_value)
+            .map_err(|e| PyValueError::new_err(format!("Invalid policy JSON for '{}': {}", policy_id, e)))?;
+
+        self.inner.lock().unwrap().add(policy).map_err(|e| {
+            PyValueError::new_err(format!("Failed to add policy '{}': {}", policy_id, e))
+        })
+    }
+
+    /// Remove a static policy from the set.
+    ///
+    /// Args:
+    ///     policy_id (str): The policy identifier to remove
+    ///
+    /// Raises:
+    ///     ValueError: If no such static policy exists
+    fn remove_policy(&mut self, policy_id: String) -> PyResult<()> {
+        let pid = PolicyId::from_str(&policy_id)
+            .map_err(|e| PyValueError::new_err(format!("Invalid policy id '{}': {}", policy_id, e)))?;
+
+        self.inner
+            .lock()
+            .unwrap()
+            .remove_static(pid)
+            .map(|_| ())
+            .map_err(|e| PyValueError::new_err(format!("Failed to remove policy '{}': {}", policy_id, e)))
     }
 
     /// Add a policy template to the set.
@@ -153,6 +369,9 @@ impl PolicySet {
     /// Args:
     ///     template (PolicyTemplate): The policy template to add
     ///
+    /// Raises:
+    ///     ValueError: If `template.template_id` is already in use
+    ///
     /// Example:
     ///     >>> template = PolicyTemplate("view-template", '''
     ///     ...     permit(
@@ -163,11 +382,44 @@ impl PolicySet {
     ///     ... ''')
     ///     >>> policy_set.add_template(template)
     fn add_template(&mut self, template: &PolicyTemplate) -> PyResult<()> {
-        self.templates.insert(
-            template.get_template_id().to_string(),
-            template.get_template_text().to_string(),
-        );
-        Ok(())
+        let template_id = template.get_template_id();
+        let tid = PolicyId::from_str(template_id)
+            .map_err(|e| PyValueError::new_err(format!("Invalid template id '{}': {}", template_id, e)))?;
+
+        let cedar_template = Template::from_str(template.get_template_text())
+            .map_err(|e| PyValueError::new_err(format!("Invalid template: {}", e)))?
+            .new_id(tid);
+
+        self.inner
+            .lock()
+            .unwrap()
+            .add_template(cedar_template)
+            .map_err(|e| {
+                PyValueError::new_err(format!("Failed to add template '{}': {}", template_id, e))
+            })
+    }
+
+    /// Remove a policy template from the set.
+    ///
+    /// Args:
+    ///     template_id (str): The template identifier to remove
+    ///
+    /// Raises:
+    ///     ValueError: If the template doesn't exist, or it still has linked
+    ///         policy instances
+    fn remove_template(&mut self, template_id: String) -> PyResult<()> {
+        let tid = PolicyId::from_str(&template_id).map_err(|e| {
+            PyValueError::new_err(format!("Invalid template id '{}': {}", template_id, e))
+        })?;
+
+        self.inner
+            .lock()
+            .unwrap()
+            .remove_template(tid)
+            .map(|_| ())
+            .map_err(|e| {
+                PyValueError::new_err(format!("Failed to remove template '{}': {}", template_id, e))
+            })
     }
 
     /// Add a template-linked policy to the set.
@@ -194,32 +446,111 @@ impl PolicySet {
         template_id: String,
         slots: &Bound<'_, PyDict>,
     ) -> PyResult<()> {
-        // Check if template exists
-        if !self.templates.contains_key(&template_id) {
-            return Err(PyValueError::new_err(format!(
-                "Template '{}' not found",
-                template_id
-            )));
-        }
+        let pid = PolicyId::from_str(&policy_id)
+            .map_err(|e| PyValueError::new_err(format!("Invalid policy id '{}': {}", policy_id, e)))?;
+        let tid = PolicyId::from_str(&template_id).map_err(|e| {
+            PyValueError::new_err(format!("Invalid template id '{}': {}", template_id, e))
+        })?;
 
-        // Convert PyDict to HashMap and validate entity UIDs
-        let mut slot_map = HashMap::new();
+        let mut cedar_slots = HashMap::new();
         for (key, value) in slots.iter() {
             let key_str: String = key.extract()?;
             let value_str: String = value.extract()?;
 
-            // Validate that the value is a valid entity UID
-            EntityUid::from_str(&value_str).map_err(|e| {
+            let entity_uid = EntityUid::from_str(&value_str).map_err(|e| {
                 PyValueError::new_err(format!("Invalid entity UID '{}': {}", value_str, e))
             })?;
 
-            slot_map.insert(key_str, value_str);
+            let slot_id = match key_str.as_str() {
+                "principal" => SlotId::principal(),
+                "resource" => SlotId::resource(),
+                other => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown slot name '{}'",
+                        other
+                    )))
+                }
+            };
+
+            cedar_slots.insert(slot_id, entity_uid);
         }
 
-        // Store the template link
-        self.template_links
-            .insert(policy_id, (template_id, slot_map));
-        Ok(())
+        self.inner.lock().unwrap().link(tid, pid, cedar_slots).map_err(|e| {
+            PyValueError::new_err(format!("Failed to link policy '{}': {}", policy_id, e))
+        })
+    }
+
+    /// Unlink a template-linked policy from the set.
+    ///
+    /// Args:
+    ///     policy_id (str): The linked policy identifier to remove
+    ///
+    /// Raises:
+    ///     ValueError: If no such linked policy exists
+    fn unlink(&mut self, policy_id: String) -> PyResult<()> {
+        let pid = PolicyId::from_str(&policy_id)
+            .map_err(|e| PyValueError::new_err(format!("Invalid policy id '{}': {}", policy_id, e)))?;
+
+        self.inner
+            .lock()
+            .unwrap()
+            .unlink(pid)
+            .map(|_| ())
+            .map_err(|e| PyValueError::new_err(format!("Failed to unlink policy '{}': {}", policy_id, e)))
+    }
+
+    /// Get the policy IDs instantiated from a template.
+    ///
+    /// Args:
+    ///     template_id (str): The template identifier
+    ///
+    /// Returns:
+    ///     list[str]: The IDs of policies currently linked to that template
+    ///
+    /// Raises:
+    ///     ValueError: If no such template exists
+    fn get_linked_policies(&self, template_id: &str) -> PyResult<Vec<String>> {
+        let tid = PolicyId::from_str(template_id).map_err(|e| {
+            PyValueError::new_err(format!("Invalid template id '{}': {}", template_id, e))
+        })?;
+
+        self.inner
+            .lock()
+            .unwrap()
+            .get_linked_policies(&tid)
+            .map(|ids| ids.map(|id| id.to_string()).collect())
+            .map_err(|e| {
+                PyValueError::new_err(format!(
+                    "Failed to get linked policies for template '{}': {}",
+                    template_id, e
+                ))
+            })
+    }
+
+    /// Get the IDs of every policy in the set, static or template-linked.
+    ///
+    /// Returns:
+    ///     list[str]: The policy IDs
+    fn policy_ids(&self) -> Vec<String> {
+        self.inner
+            .lock()
+            .unwrap()
+            .policies()
+            .map(|p| p.id().to_string())
+            .collect()
+    }
+
+    /// Get the IDs of every template in the set.
+    ///
+    /// Returns:
+    ///     list[str]: The template IDs
+    fn template_ids(&self) -> Vec<String> {
+        self.inner
+            .lock()
+            .unwrap()
+            .templates()
+            .map(|t| t.id().to_string())
+            .collect()
     }
 
     /// Get the number of policies in the set (including template-linked policies).
@@ -227,24 +558,31 @@ impl PolicySet {
     /// Returns:
     ///     int: The number of policies
     fn __len__(&self) -> usize {
-        self.policies.len() + self.template_links.len()
+        self.inner.lock().unwrap().policies().count()
     }
 
     /// String representation of the policy set.
     fn __repr__(&self) -> String {
-        format!("PolicySet(policies={})", self.policies.len())
+        let inner = self.inner.lock().unwrap();
+        format!(
+            "PolicySet(policies={}, templates={})",
+            inner.policies().count(),
+            inner.templates().count()
+        )
     }
 
     /// Support for copy.copy() - creates a shallow copy.
     ///
     /// Returns:
-    ///     PolicySet: A new PolicySet instance with copied data
+    ///     PolicySet: A new PolicySet instance with copied data. The copy is
+    ///     not linked to the original's directory/watcher.
     fn __copy__(&self) -> Self {
         PolicySet {
-            policies: self.policies.clone(),
-            templates: self.templates.clone(),
-            template_links: self.template_links.clone(),
+            inner: Arc::new(Mutex::new(self.inner.lock().unwrap().clone())),
             next_auto_id: self.next_auto_id,
+            directory: None,
+            last_reload_error: Arc::new(Mutex::new(None)),
+            _watcher: Mutex::new(None),
         }
     }
 
@@ -254,112 +592,91 @@ impl PolicySet {
     ///     memo (dict): Dictionary for memoization (unused but required by protocol)
     ///
     /// Returns:
-    ///     PolicySet: A new PolicySet instance with deeply copied data
+    ///     PolicySet: A new PolicySet instance with deeply copied data. The
+    ///     copy is not linked to the original's directory/watcher.
     fn __deepcopy__(&self, _memo: &Bound<'_, PyDict>) -> Self {
-        // Since all our data is owned (HashMap of Strings), clone is effectively a deep copy
-        PolicySet {
-            policies: self.policies.clone(),
-            templates: self.templates.clone(),
-            template_links: self.template_links.clone(),
-            next_auto_id: self.next_auto_id,
-        }
+        self.__copy__()
     }
 }
 
 impl PolicySet {
-    /// Convert to a Cedar PolicySet (internal use).
+    /// Get the underlying Cedar PolicySet (internal use).
     pub(crate) fn get_cedar_policy_set(&self) -> CedarPolicySet {
-        let mut combined_text = String::new();
-        let mut template_id_map: HashMap<String, String> = HashMap::new();
-        let mut auto_id_counter = 0;
-
-        // Build combined text with all policies
-        for (_id, policy_text) in &self.policies {
-            combined_text.push_str(policy_text);
-            combined_text.push_str("\n\n");
-            auto_id_counter += 1;
-        }
+        self.inner.lock().unwrap().clone()
+    }
 
-        // Add templates to the combined text
-        for (template_id, template_text) in &self.templates {
-            combined_text.push_str(template_text);
-            combined_text.push_str("\n\n");
-            // Track the auto-assigned ID for this template
-            let auto_id = format!("policy{}", auto_id_counter);
-            template_id_map.insert(template_id.clone(), auto_id);
-            auto_id_counter += 1;
+    /// Parse every `*.cedar`/`*.cedartemplate` file in `dir` into one
+    /// `CedarPolicySet`, keyed by filename-derived IDs (internal use, also
+    /// reused by `PolicyStore`).
+    pub(crate) fn load_from_directory(dir: &Path) -> Result<CedarPolicySet, String> {
+        let mut policy_set = CedarPolicySet::new();
+
+        let entries = std::fs::read_dir(dir).map_err(|e| e.to_string())?;
+        for entry in entries {
+            let entry = entry.map_err(|e| e.to_string())?;
+            let path = entry.path();
+            let extension = path.extension().and_then(|e| e.to_str());
+            let stem = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default()
+                .to_string();
+
+            match extension {
+                Some("cedar") => {
+                    let text = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+                    let pid = PolicyId::from_str(&stem).map_err(|e| e.to_string())?;
+                    let policy = Policy::from_str(&text)
+                        .map_err(|e| format!("{}: {}", path.display(), e))?
+                        .new_id(pid);
+                    policy_set
+                        .add(policy)
+                        .map_err(|e| format!("{}: {}", path.display(), e))?;
+                }
+                Some("cedartemplate") => {
+                    let text = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+                    let tid = PolicyId::from_str(&stem).map_err(|e| e.to_string())?;
+                    let template = Template::from_str(&text)
+                        .map_err(|e| format!("{}: {}", path.display(), e))?
+                        .new_id(tid);
+                    policy_set
+                        .add_template(template)
+                        .map_err(|e| format!("{}: {}", path.display(), e))?;
+                }
+                _ => {}
+            }
         }
 
-        // Parse the combined policy set text
-        let mut policy_set = match CedarPolicySet::from_str(&combined_text) {
-            Ok(ps) => ps,
-            Err(e) => {
-                eprintln!("Warning: Failed to parse combined policy set: {}", e);
-                return CedarPolicySet::new();
-            }
-        };
+        Ok(policy_set)
+    }
 
-        // Add template-linked policies
-        for (policy_id, (template_id, slots)) in &self.template_links {
-            // Get the auto-assigned template ID
-            let auto_template_id = match template_id_map.get(template_id) {
-                Some(id) => id,
-                None => {
-                    eprintln!(
-                        "Warning: Template '{}' not found for policy '{}'",
-                        template_id, policy_id
-                    );
-                    continue;
+    /// Spawn a background `notify` watcher that reloads this set whenever a
+    /// file under its source directory changes (internal use).
+    fn start_watching(&mut self) -> PyResult<()> {
+        let dir = self
+            .directory
+            .clone()
+            .ok_or_else(|| PyValueError::new_err("No directory to watch"))?;
+
+        let inner = Arc::clone(&self.inner);
+        let last_reload_error = Arc::clone(&self.last_reload_error);
+        let watch_dir = dir.clone();
+
+        let watcher = crate::fs_watch::watch_for_changes(&dir, move || {
+            match PolicySet::load_from_directory(&watch_dir) {
+                Ok(reloaded) => {
+                    *inner.lock().unwrap() = reloaded;
+                    *last_reload_error.lock().unwrap() = None;
                 }
-            };
-
-            let tid = match PolicyId::from_str(auto_template_id) {
-                Ok(id) => id,
                 Err(e) => {
-                    eprintln!("Warning: Invalid template ID '{}': {}", auto_template_id, e);
-                    continue;
-                }
-            };
-
-            // Convert slot map to Cedar format
-            let mut cedar_slots = HashMap::new();
-            for (slot_name, entity_uid_str) in slots {
-                let slot_id = match slot_name.as_str() {
-                    "principal" => SlotId::principal(),
-                    "resource" => SlotId::resource(),
-                    _ => {
-                        eprintln!(
-                            "Warning: Unknown slot name '{}' in policy '{}'",
-                            slot_name, policy_id
-                        );
-                        continue;
-                    }
-                };
-
-                if let Ok(entity_uid) = EntityUid::from_str(entity_uid_str) {
-                    cedar_slots.insert(slot_id, entity_uid);
-                } else {
-                    eprintln!(
-                        "Warning: Invalid entity UID '{}' in policy '{}'",
-                        entity_uid_str, policy_id
-                    );
+                    // Keep serving the previous good policies; just record
+                    // why the reload was rejected.
+                    *last_reload_error.lock().unwrap() = Some(e);
                 }
             }
+        })?;
 
-            // Create the linked policy
-            let pid = match PolicyId::from_str(policy_id) {
-                Ok(id) => id,
-                Err(e) => {
-                    eprintln!("Warning: Invalid policy ID '{}': {}", policy_id, e);
-                    continue;
-                }
-            };
-
-            policy_set.link(tid, pid, cedar_slots).unwrap_or_else(|e| {
-                eprintln!("Warning: Failed to link policy '{}': {}", policy_id, e);
-            });
-        }
-
-        policy_set
+        *self._watcher.lock().unwrap() = Some(watcher);
+        Ok(())
     }
 }
@@ -0,0 +1,178 @@
+use cedar_policy::{PolicySet as CedarPolicySet, Schema};
+use notify::RecommendedWatcher;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use crate::policy_set::PolicySet;
+use crate::schema::CedarSchema;
+
+/// A `PolicySet` backed by a file or directory on disk, for a long-lived
+/// service that needs to authorize against the latest policies without
+/// restarting.
+///
+/// Like `PolicySet.from_directory`, it can be reloaded manually (`reload()`)
+/// or by a background `watch=True` filesystem watcher; unlike it, the path
+/// may also be a single `.cedar` file and the store can carry a schema. It
+/// is handed directly to `is_authorized`/`is_authorized_partial`, each of
+/// which uses the policy set loaded at the moment the call starts: a reload
+/// takes effect from the next call on, not for calls already in progress.
+///
+/// Example:
+///     >>> store = PolicyStore("./policies", schema=schema, watch=True)
+///     >>> is_authorized(request, store)
+#[pyclass]
+pub struct PolicyStore {
+    inner: Arc<Mutex<CedarPolicySet>>,
+    schema: Option<Schema>,
+    path: PathBuf,
+    last_reload_error: Arc<Mutex<Option<String>>>,
+    // Kept alive only to keep the background filesystem watcher running;
+    // never read.
+    _watcher: Mutex<Option<RecommendedWatcher>>,
+}
+
+#[pymethods]
+impl PolicyStore {
+    /// Load a PolicySet (and optionally a schema) from a `.cedar` file or a
+    /// directory of `.cedar`/`.cedartemplate` files.
+    ///
+    /// Args:
+    ///     path (str): A `.cedar` policy file, or a directory of them (see
+    ///         `PolicySet.from_directory`)
+    ///     schema (CedarSchema, optional): Schema to validate entities
+    ///         against when this store is used for authorization
+    ///     watch (bool, optional): If True, spawn a background filesystem
+    ///         watcher that atomically swaps in the recompiled policy set
+    ///         whenever `path` changes (default: False)
+    ///
+    /// Raises:
+    ///     ValueError: If `path` can't be read, or any policy in it fails to parse
+    ///
+    /// Example:
+    ///     >>> store = PolicyStore("./policies", schema=schema, watch=True)
+    #[new]
+    #[pyo3(signature = (path, schema=None, watch=false))]
+    fn new(path: String, schema: Option<&CedarSchema>, watch: bool) -> PyResult<Self> {
+        let path_buf = PathBuf::from(&path);
+        let loaded = load_policies(&path_buf)
+            .map_err(|e| PyValueError::new_err(format!("Failed to load '{}': {}", path, e)))?;
+
+        let mut store = PolicyStore {
+            inner: Arc::new(Mutex::new(loaded)),
+            schema: schema.map(|s| s.get_schema().clone()),
+            path: path_buf,
+            last_reload_error: Arc::new(Mutex::new(None)),
+            _watcher: Mutex::new(None),
+        };
+
+        if watch {
+            store.start_watching()?;
+        }
+
+        Ok(store)
+    }
+
+    /// Reload the policy set from `path`.
+    ///
+    /// If the files on disk fail to parse, the previous in-force policy set
+    /// is left untouched and the failure is recorded in `last_reload_error`
+    /// (and raised here).
+    ///
+    /// Raises:
+    ///     ValueError: If the reload fails
+    fn reload(&mut self) -> PyResult<()> {
+        match load_policies(&self.path) {
+            Ok(reloaded) => {
+                *self.inner.lock().unwrap() = reloaded;
+                *self.last_reload_error.lock().unwrap() = None;
+                Ok(())
+            }
+            Err(e) => {
+                *self.last_reload_error.lock().unwrap() = Some(e.clone());
+                Err(PyValueError::new_err(format!(
+                    "Failed to reload policies: {}",
+                    e
+                )))
+            }
+        }
+    }
+
+    /// The most recent reload failure, if any.
+    ///
+    /// Returns:
+    ///     str or None: The error from the last failed `reload()` (manual or
+    ///     watcher-triggered), or None if the last reload succeeded
+    #[getter]
+    fn last_reload_error(&self) -> Option<String> {
+        self.last_reload_error.lock().unwrap().clone()
+    }
+
+    /// Get the number of policies currently loaded (including template-linked policies).
+    ///
+    /// Returns:
+    ///     int: The number of policies
+    fn __len__(&self) -> usize {
+        self.inner.lock().unwrap().policies().count()
+    }
+
+    /// String representation of the policy store.
+    fn __repr__(&self) -> String {
+        format!(
+            "PolicyStore(path='{}', policies={})",
+            self.path.display(),
+            self.inner.lock().unwrap().policies().count()
+        )
+    }
+}
+
+impl PolicyStore {
+    /// Get the underlying Cedar PolicySet (internal use, also called by
+    /// `is_authorized`/`is_authorized_partial` via `PolicyProvider`).
+    pub(crate) fn get_cedar_policy_set(&self) -> CedarPolicySet {
+        self.inner.lock().unwrap().clone()
+    }
+
+    /// Get the schema this store was constructed with, if any (internal use).
+    pub(crate) fn schema(&self) -> Option<&Schema> {
+        self.schema.as_ref()
+    }
+
+    /// Spawn a background `notify` watcher that reloads this store whenever
+    /// `path` changes (internal use).
+    fn start_watching(&mut self) -> PyResult<()> {
+        let inner = Arc::clone(&self.inner);
+        let last_reload_error = Arc::clone(&self.last_reload_error);
+        let watch_path = self.path.clone();
+
+        let watcher = crate::fs_watch::watch_for_changes(&self.path, move || {
+            match load_policies(&watch_path) {
+                Ok(reloaded) => {
+                    *inner.lock().unwrap() = reloaded;
+                    *last_reload_error.lock().unwrap() = None;
+                }
+                Err(e) => {
+                    // Keep serving the previous good policies; just record
+                    // why the reload was rejected.
+                    *last_reload_error.lock().unwrap() = Some(e);
+                }
+            }
+        })?;
+
+        *self._watcher.lock().unwrap() = Some(watcher);
+        Ok(())
+    }
+}
+
+/// Load a single `.cedar` file, or every `.cedar`/`.cedartemplate` file in a
+/// directory, into one `CedarPolicySet` (internal use).
+fn load_policies(path: &Path) -> Result<CedarPolicySet, String> {
+    if path.is_dir() {
+        PolicySet::load_from_directory(path)
+    } else {
+        let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        CedarPolicySet::from_str(&text).map_err(|e| e.to_string())
+    }
+}
@@ -1,4 +1,4 @@
-use cedar_policy::{EntityUid, Template};
+use cedar_policy::{EntityUid, PolicyId, Template};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -114,6 +114,62 @@ impl PolicyTemplate {
         Ok((policy_id, self.template_id.clone(), slot_map))
     }
 
+    /// Export this template to its canonical JSON (EST) representation.
+    ///
+    /// Lets the template be persisted in a structured database, diffed
+    /// programmatically, or built from a UI, instead of being limited to
+    /// Cedar's text syntax.
+    ///
+    /// Returns:
+    ///     str: The JSON-encoded template
+    ///
+    /// Raises:
+    ///     ValueError: If the template text can't be serialized to JSON
+    fn to_json(&self) -> PyResult<String> {
+        let template = Template::from_str(&self.template_text)
+            .map_err(|e| PyValueError::new_err(format!("Invalid template: {}", e)))?;
+
+        let json_value = template
+            .to_json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to serialize template: {}", e)))?;
+
+        serde_json::to_string(&json_value)
+            .map_err(|e| PyValueError::new_err(format!("Failed to serialize template: {}", e)))
+    }
+
+    /// Construct a template from its JSON (EST) representation.
+    ///
+    /// Args:
+    ///     template_id (str): Unique identifier for the template
+    ///     template_json (str): The JSON-encoded template (EST form)
+    ///
+    /// Returns:
+    ///     PolicyTemplate: The parsed template
+    ///
+    /// Raises:
+    ///     ValueError: If the JSON is invalid
+    #[classmethod]
+    fn from_json(
+        _cls: &Bound<'_, pyo3::types::PyType>,
+        template_id: String,
+        template_json: &str,
+    ) -> PyResult<Self> {
+        let json_value: serde_json::Value = serde_json::from_str(template_json)
+            .map_err(|e| PyValueError::new_err(format!("Invalid template JSON: {}", e)))?;
+
+        let pid = PolicyId::from_str(&template_id).map_err(|e| {
+            PyValueError::new_err(format!("Invalid template id '{}': {}", template_id, e))
+        })?;
+
+        let template = Template::from_json(Some(pid), json_value)
+            .map_err(|e| PyValueError::new_err(format!("Invalid template JSON: {}", e)))?;
+
+        Ok(PolicyTemplate {
+            template_id,
+            template_text: template.to_string(),
+        })
+    }
+
     /// String representation of the template.
     fn __repr__(&self) -> String {
         format!("PolicyTemplate(id='{}', slots=...)", self.template_id)
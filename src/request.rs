@@ -1,6 +1,6 @@
-use crate::context_utils::py_dict_to_context;
+use crate::context_utils::py_dict_to_context_pairs;
 use crate::schema::CedarSchema;
-use cedar_policy::{Context, EntityUid, Request as CedarRequest, Schema};
+use cedar_policy::{Context, EntityUid, Request as CedarRequest, RestrictedExpression, Schema};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -10,13 +10,20 @@ use std::str::FromStr;
 ///
 /// This represents a request to authorize whether a principal can perform
 /// an action on a resource, optionally with additional context.
+///
+/// `principal`, `action`, `resource`, and individual context keys may all be
+/// left unknown (by passing `None` or listing them in
+/// `unknown_context_keys`) when the request is only ever going to be used
+/// with `is_authorized_partial`. A concrete `is_authorized` call still
+/// requires every slot to be known.
 #[pyclass]
 pub struct Request {
-    principal: String,
-    action: String,
-    resource: String,
-    context: Option<Context>, // Store the actual Cedar Context
-    schema: Option<Schema>,   // Store the actual Cedar Schema
+    principal: Option<String>,
+    action: Option<String>,
+    resource: Option<String>,
+    context_pairs: Vec<(String, RestrictedExpression)>,
+    unknown_context_keys: Vec<String>,
+    schema: Option<Schema>, // Store the actual Cedar Schema
 }
 
 #[pymethods]
@@ -24,10 +31,15 @@ impl Request {
     /// Create a new authorization request.
     ///
     /// Args:
-    ///     principal (str): The principal entity (e.g., 'User::"alice"')
-    ///     action (str): The action entity (e.g., 'Action::"view"')
-    ///     resource (str): The resource entity (e.g., 'Document::"report"')
+    ///     principal (str, optional): The principal entity (e.g., 'User::"alice"').
+    ///         Leave as None to mark it unknown for partial evaluation.
+    ///     action (str, optional): The action entity (e.g., 'Action::"view"').
+    ///         Leave as None to mark it unknown for partial evaluation.
+    ///     resource (str, optional): The resource entity (e.g., 'Document::"report"').
+    ///         Leave as None to mark it unknown for partial evaluation.
     ///     context (dict, optional): Optional context data as a dictionary
+    ///     unknown_context_keys (list[str], optional): Names of additional
+    ///         context keys to leave unknown for partial evaluation
     ///     schema (CedarSchema, optional): Optional schema for request validation
     ///
     /// Example:
@@ -37,19 +49,27 @@ impl Request {
     ///     ...     resource='Document::"report"',
     ///     ...     context={"ip_address": "192.168.1.1", "authenticated": True}
     ///     ... )
+    ///
+    ///     >>> # Leave the resource unknown to ask "what can alice view?"
+    ///     >>> partial_req = Request(
+    ///     ...     principal='User::"alice"',
+    ///     ...     action='Action::"view"',
+    ///     ...     resource=None,
+    ///     ... )
     #[new]
-    #[pyo3(signature = (principal, action, resource, context=None, schema=None))]
+    #[pyo3(signature = (principal=None, action=None, resource=None, context=None, unknown_context_keys=None, schema=None))]
     fn new(
-        principal: String,
-        action: String,
-        resource: String,
+        principal: Option<String>,
+        action: Option<String>,
+        resource: Option<String>,
         context: Option<Bound<'_, PyDict>>,
+        unknown_context_keys: Option<Vec<String>>,
         schema: Option<&CedarSchema>,
     ) -> PyResult<Self> {
-        let cedar_context = if let Some(ctx_dict) = context {
-            Some(py_dict_to_context(&ctx_dict)?)
+        let context_pairs = if let Some(ctx_dict) = context {
+            py_dict_to_context_pairs(&ctx_dict)?
         } else {
-            None
+            Vec::new()
         };
 
         let cedar_schema = schema.map(|s| s.get_schema().clone());
@@ -58,7 +78,8 @@ impl Request {
             principal,
             action,
             resource,
-            context: cedar_context,
+            context_pairs,
+            unknown_context_keys: unknown_context_keys.unwrap_or_default(),
             schema: cedar_schema,
         })
     }
@@ -66,33 +87,112 @@ impl Request {
     /// String representation of the request.
     fn __repr__(&self) -> String {
         format!(
-            "Request(principal='{}', action='{}', resource='{}')",
+            "Request(principal={:?}, action={:?}, resource={:?})",
             self.principal, self.action, self.resource
         )
     }
 }
 
 impl Request {
-    /// Convert to a Cedar Request (internal use).
+    /// Parse a Cedar entity UID string, tagging the error with the slot name.
+    fn parse_entity_uid(field: &str, value: &str) -> PyResult<EntityUid> {
+        EntityUid::from_str(value)
+            .map_err(|e| PyValueError::new_err(format!("Invalid {}: {}", field, e)))
+    }
+
+    /// Convert to a fully-concrete Cedar Request (internal use).
+    ///
+    /// Errors if any of principal/action/resource or a declared context key
+    /// is still unknown; use `to_cedar_partial_request` for those.
     pub(crate) fn to_cedar_request(&self) -> PyResult<CedarRequest> {
-        // Parse the entity UIDs
-        let principal = EntityUid::from_str(&self.principal)
-            .map_err(|e| PyValueError::new_err(format!("Invalid principal: {}", e)))?;
+        if !self.unknown_context_keys.is_empty() {
+            return Err(PyValueError::new_err(
+                "Request has unknown context keys; use is_authorized_partial instead",
+            ));
+        }
 
-        let action = EntityUid::from_str(&self.action)
-            .map_err(|e| PyValueError::new_err(format!("Invalid action: {}", e)))?;
+        let principal_str = self
+            .principal
+            .as_deref()
+            .ok_or_else(|| PyValueError::new_err("Request has an unknown principal; use is_authorized_partial instead"))?;
+        let action_str = self.action.as_deref().ok_or_else(|| {
+            PyValueError::new_err("Request has an unknown action; use is_authorized_partial instead")
+        })?;
+        let resource_str = self
+            .resource
+            .as_deref()
+            .ok_or_else(|| PyValueError::new_err("Request has an unknown resource; use is_authorized_partial instead"))?;
 
-        let resource = EntityUid::from_str(&self.resource)
-            .map_err(|e| PyValueError::new_err(format!("Invalid resource: {}", e)))?;
+        let principal = Self::parse_entity_uid("principal", principal_str)?;
+        let action = Self::parse_entity_uid("action", action_str)?;
+        let resource = Self::parse_entity_uid("resource", resource_str)?;
 
-        // Use the stored context or create an empty one
-        let context = self.context.clone().unwrap_or_else(Context::empty);
+        let context = Context::from_pairs(self.context_pairs.clone())
+            .map_err(|e| PyValueError::new_err(format!("Failed to create context: {}", e)))?;
 
-        // Get schema reference if available
         let schema_ref = self.schema.as_ref();
 
-        // Build the request
         CedarRequest::new(principal, action, resource, context, schema_ref)
             .map_err(|e| PyValueError::new_err(format!("Failed to create request: {}", e)))
     }
+
+    /// Convert to a (possibly partial) Cedar Request for partial evaluation
+    /// (internal use). Slots left as `None`, and context keys listed in
+    /// `unknown_context_keys`, are left symbolic instead of erroring.
+    pub(crate) fn to_cedar_partial_request(&self) -> PyResult<CedarRequest> {
+        let mut builder = CedarRequest::builder();
+
+        if let Some(principal) = &self.principal {
+            builder = builder.principal(Self::parse_entity_uid("principal", principal)?);
+        }
+        if let Some(action) = &self.action {
+            builder = builder.action(Self::parse_entity_uid("action", action)?);
+        }
+        if let Some(resource) = &self.resource {
+            builder = builder.resource(Self::parse_entity_uid("resource", resource)?);
+        }
+
+        let mut pairs = self.context_pairs.clone();
+        for key in &self.unknown_context_keys {
+            pairs.push((key.clone(), RestrictedExpression::new_unknown(key)));
+        }
+        let context = Context::from_pairs(pairs)
+            .map_err(|e| PyValueError::new_err(format!("Failed to create context: {}", e)))?;
+        builder = builder.context(context);
+
+        if let Some(schema) = &self.schema {
+            builder = builder.schema(schema);
+        }
+
+        builder
+            .build_for_partial_eval()
+            .map_err(|e| PyValueError::new_err(format!("Failed to create partial request: {}", e)))
+    }
+
+    /// Known principal/action/resource strings and unknown-context-key
+    /// declarations, exposed so `PartialDecision::reauthorize` can rebuild a
+    /// narrower request without re-parsing Python arguments.
+    pub(crate) fn principal(&self) -> Option<&str> {
+        self.principal.as_deref()
+    }
+
+    pub(crate) fn action(&self) -> Option<&str> {
+        self.action.as_deref()
+    }
+
+    pub(crate) fn resource(&self) -> Option<&str> {
+        self.resource.as_deref()
+    }
+
+    pub(crate) fn context_pairs(&self) -> &[(String, RestrictedExpression)] {
+        &self.context_pairs
+    }
+
+    pub(crate) fn unknown_context_keys(&self) -> &[String] {
+        &self.unknown_context_keys
+    }
+
+    pub(crate) fn schema(&self) -> Option<&Schema> {
+        self.schema.as_ref()
+    }
 }
@@ -55,6 +55,231 @@ impl CedarSchema {
     }
 }
 
+/// Parse a `mode` string into a Cedar `ValidationMode`.
+pub(crate) fn parse_validation_mode(mode: &str) -> PyResult<ValidationMode> {
+    match mode {
+        "strict" => Ok(ValidationMode::Strict),
+        "permissive" => Ok(ValidationMode::Permissive),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid validation mode '{}'. Expected 'strict' or 'permissive'",
+            mode
+        ))),
+    }
+}
+
+/// A source span (1-indexed line/column, start inclusive and end exclusive)
+/// pointing at the part of a policy a diagnostic was raised against.
+#[pyclass]
+#[derive(Clone)]
+pub struct SourceSpan {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[pymethods]
+impl SourceSpan {
+    #[getter]
+    fn start_line(&self) -> usize {
+        self.start_line
+    }
+
+    #[getter]
+    fn start_column(&self) -> usize {
+        self.start_column
+    }
+
+    #[getter]
+    fn end_line(&self) -> usize {
+        self.end_line
+    }
+
+    #[getter]
+    fn end_column(&self) -> usize {
+        self.end_column
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SourceSpan({}:{}-{}:{})",
+            self.start_line, self.start_column, self.end_line, self.end_column
+        )
+    }
+}
+
+/// A single, structured error or warning produced by validating a policy
+/// against a schema.
+///
+/// Unlike the flat `"Error: ..."` strings `validate_policies` used to
+/// return, every field here is pulled directly from Cedar's
+/// `ValidationError`/`ValidationWarning` accessors so IDE integrations and
+/// CI tooling can highlight the exact policy and location that failed
+/// without regex-parsing a message.
+#[pyclass]
+#[derive(Clone)]
+pub struct ValidationDiagnostic {
+    severity: String,
+    policy_id: String,
+    span: Option<SourceSpan>,
+    kind: String,
+    message: String,
+}
+
+#[pymethods]
+impl ValidationDiagnostic {
+    /// "error" or "warning".
+    #[getter]
+    fn severity(&self) -> String {
+        self.severity.clone()
+    }
+
+    /// The id of the policy this diagnostic was raised against.
+    #[getter]
+    fn policy_id(&self) -> String {
+        self.policy_id.clone()
+    }
+
+    /// The source span within the policy text this diagnostic points at, if
+    /// Cedar could attribute one.
+    #[getter]
+    fn span(&self) -> Option<SourceSpan> {
+        self.span.clone()
+    }
+
+    /// A stable category string for this diagnostic (e.g.
+    /// `"UnrecognizedEntityType"`), suitable for grouping/filtering in CI.
+    #[getter]
+    fn kind(&self) -> String {
+        self.kind.clone()
+    }
+
+    /// The human-readable message describing the issue.
+    #[getter]
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ValidationDiagnostic(severity='{}', policy_id='{}', kind='{}', message='{}')",
+            self.severity, self.policy_id, self.kind, self.message
+        )
+    }
+}
+
+/// The result of validating a `PolicySet` against a `CedarSchema`.
+#[pyclass]
+pub struct ValidationResult {
+    passed: bool,
+    errors: Vec<ValidationDiagnostic>,
+    warnings: Vec<ValidationDiagnostic>,
+}
+
+#[pymethods]
+impl ValidationResult {
+    /// Whether validation found no errors (warnings are still allowed).
+    #[getter]
+    fn passed(&self) -> bool {
+        self.passed
+    }
+
+    /// The validation errors, one per undeclared type, bad attribute
+    /// reference, impossible action/resource pairing, etc.
+    #[getter]
+    fn errors(&self) -> Vec<ValidationDiagnostic> {
+        self.errors.clone()
+    }
+
+    /// The validation warnings (non-fatal issues, e.g. in permissive mode).
+    #[getter]
+    fn warnings(&self) -> Vec<ValidationDiagnostic> {
+        self.warnings.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ValidationResult(passed={}, errors={}, warnings={})",
+            self.passed,
+            self.errors.len(),
+            self.warnings.len()
+        )
+    }
+
+    /// Boolean conversion - True if validation passed.
+    fn __bool__(&self) -> bool {
+        self.passed
+    }
+}
+
+/// Pull a `SourceSpan` out of anything exposing Cedar's `Loc`-style source
+/// location accessors, if one was attached to the underlying error.
+fn extract_span(loc: Option<&cedar_policy::Loc>) -> Option<SourceSpan> {
+    let loc = loc?;
+    let (start_line, start_column) = loc.line_col(loc.start());
+    let (end_line, end_column) = loc.line_col(loc.end());
+    Some(SourceSpan {
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+    })
+}
+
+/// Render a validation error/warning kind's variant name (e.g.
+/// `"UnrecognizedEntityType"`) without its payload, for a stable,
+/// filterable category string.
+fn kind_name<T: std::fmt::Debug>(kind: &T) -> String {
+    let debug = format!("{:?}", kind);
+    debug
+        .split(['(', ' ', '{'])
+        .next()
+        .unwrap_or(&debug)
+        .to_string()
+}
+
+impl ValidationResult {
+    /// Run the validator and collect its findings into a `ValidationResult`
+    /// (internal use, shared by `PolicySet.validate` and `validate_policies`).
+    pub(crate) fn from_validation(
+        policy_set: &cedar_policy::PolicySet,
+        schema: &CedarSchema,
+        mode: &str,
+    ) -> PyResult<Self> {
+        let validation_mode = parse_validation_mode(mode)?;
+        let validator = Validator::new(schema.get_schema().clone());
+        let result = validator.validate(policy_set, validation_mode);
+
+        let errors = result
+            .validation_errors()
+            .map(|e| ValidationDiagnostic {
+                severity: "error".to_string(),
+                policy_id: e.policy_id().to_string(),
+                span: extract_span(e.source_loc()),
+                kind: kind_name(e.error_kind()),
+                message: e.to_string(),
+            })
+            .collect();
+
+        let warnings = result
+            .validation_warnings()
+            .map(|w| ValidationDiagnostic {
+                severity: "warning".to_string(),
+                policy_id: w.policy_id().to_string(),
+                span: extract_span(w.source_loc()),
+                kind: kind_name(w.warning_kind()),
+                message: w.to_string(),
+            })
+            .collect();
+
+        Ok(ValidationResult {
+            passed: result.validation_passed(),
+            errors,
+            warnings,
+        })
+    }
+}
+
 /// Validate policies against a schema.
 ///
 /// Args:
@@ -63,52 +288,24 @@ impl CedarSchema {
 ///     mode (str, optional): Validation mode - "strict" or "permissive" (default: "strict")
 ///
 /// Returns:
-///     list: A list of validation error messages (empty if valid)
+///     list[ValidationDiagnostic]: One entry per error or warning (empty if
+///     valid), each carrying its severity, policy id, source span, a stable
+///     `kind` category, and a human message
 ///
 /// Example:
-///     >>> errors = validate_policies(policies, schema)
-///     >>> if errors:
-///     ...         for error in errors:
-///     ...             print(f"Validation error: {error}")
-///     ... else:
-///     ...         print("All policies are valid!")
+///     >>> diagnostics = validate_policies(policies, schema)
+///     >>> for d in diagnostics:
+///     ...     print(f"{d.severity} in {d.policy_id}: {d.message}")
 #[pyfunction]
 #[pyo3(signature = (policies, schema, mode="strict"))]
 pub fn validate_policies(
     policies: &crate::policy_set::PolicySet,
     schema: &CedarSchema,
     mode: &str,
-) -> PyResult<Vec<String>> {
-    // Parse validation mode - Cedar 4.x only has Strict mode
-    let validation_mode = match mode {
-        "strict" => ValidationMode::Strict,
-        _ => {
-            return Err(PyValueError::new_err(format!(
-                "Invalid validation mode '{}'. Currently only 'strict' is supported",
-                mode
-            )))
-        }
-    };
-
-    // Create validator
-    let validator = Validator::new(schema.get_schema().clone());
-
-    // Get the Cedar policy set
-    let policy_set = policies.get_cedar_policy_set();
-
-    // Validate
-    let result = validator.validate(&policy_set, validation_mode);
-
-    // Collect errors and warnings
-    let mut messages = Vec::new();
-
-    for error in result.validation_errors() {
-        messages.push(format!("Error: {}", error));
-    }
-
-    for warning in result.validation_warnings() {
-        messages.push(format!("Warning: {}", warning));
-    }
+) -> PyResult<Vec<ValidationDiagnostic>> {
+    let result = ValidationResult::from_validation(&policies.get_cedar_policy_set(), schema, mode)?;
 
-    Ok(messages)
+    let mut diagnostics = result.errors;
+    diagnostics.extend(result.warnings);
+    Ok(diagnostics)
 }